@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use clap::Parser;
+use walkdir::WalkDir;
+
+use crate::convert::{convert_and_write, ConversionOptions};
+
+#[derive(Parser, Debug, Clone)]
+pub struct BatchArgs {
+    /// Directory to recursively search for .gltf/.glb files
+    #[arg(short, long)]
+    pub input_directory: String,
+
+    /// Output directory path; subdirectory structure is mirrored from the input directory
+    #[arg(short, long)]
+    pub output_directory: Option<String>,
+
+    #[command(flatten)]
+    pub options: ConversionOptions,
+}
+
+pub fn run(args: BatchArgs) {
+    let as_string = std::env::current_dir().unwrap().into_os_string();
+    let path_out = as_string.into_string().unwrap();
+
+    let input_directory = Path::new(&args.input_directory);
+    let output_directory = Path::new(args.output_directory.as_deref().unwrap_or(&path_out));
+
+    let mut succeeded = Vec::new();
+    let mut failed: Vec<(String, String)> = Vec::new();
+
+    for entry in WalkDir::new(input_directory)
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        let path = entry.path();
+        let is_gltf = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|ext| ext.eq_ignore_ascii_case("gltf") || ext.eq_ignore_ascii_case("glb"))
+            .unwrap_or(false);
+
+        if !entry.file_type().is_file() || !is_gltf {
+            continue;
+        }
+
+        let relative = path
+            .parent()
+            .unwrap_or(input_directory)
+            .strip_prefix(input_directory)
+            .unwrap_or(Path::new(""));
+        let out_dir = output_directory.join(relative);
+
+        if let Err(e) = std::fs::create_dir_all(&out_dir) {
+            failed.push((path.display().to_string(), e.to_string()));
+            continue;
+        }
+
+        let path_str = path.to_string_lossy().to_string();
+        let out_dir_str = out_dir.to_string_lossy().to_string();
+
+        match convert_and_write(&path_str, &out_dir_str, &args.options) {
+            Ok(_) => succeeded.push(path_str),
+            Err(e) => failed.push((path_str, e.to_string())),
+        }
+    }
+
+    println!(
+        "[Batch] Converted {} file(s), {} failure(s)",
+        succeeded.len(),
+        failed.len()
+    );
+    for (path, error) in &failed {
+        eprintln!("[Batch] Failed to convert {}: {}", path, error);
+    }
+}
@@ -0,0 +1,22 @@
+use clap::{Parser, Subcommand};
+
+use crate::batch::BatchArgs;
+use crate::convert::ConvertArgs;
+use crate::verify::VerifyArgs;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Decompose a single glTF/GLB file into convex hulls
+    Convert(ConvertArgs),
+    /// Recursively decompose every glTF/GLB file under a directory
+    Batch(BatchArgs),
+    /// Inspect an emitted shape file and report decomposition quality metrics
+    Verify(VerifyArgs),
+}
@@ -0,0 +1,208 @@
+use std::{
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression as GzCompression};
+
+/// Compression applied to emitted shape/obj files, and transparently undone on load.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Yaz0,
+}
+
+/// Appends the compression's file extension (if any) and compresses `bytes` accordingly.
+/// Returns the path the caller should actually write to, and the bytes to write there.
+pub fn finalize(path: &Path, bytes: &[u8], compression: Compression) -> (PathBuf, Vec<u8>) {
+    match compression {
+        Compression::None => (path.to_path_buf(), bytes.to_vec()),
+        Compression::Gzip => (with_suffix(path, "gz"), gzip_encode(bytes)),
+        Compression::Yaz0 => (with_suffix(path, "yaz0"), yaz0_encode(bytes)),
+    }
+}
+
+/// Strips a trailing `.gz`/`.yaz0` extension off `path`, returning the compression used and the
+/// path with that suffix removed (so the remaining extension still identifies the inner format).
+pub fn strip_compression_extension(path: &Path) -> (Compression, PathBuf) {
+    let as_str = path.to_string_lossy();
+    if let Some(stripped) = as_str.strip_suffix(".gz") {
+        (Compression::Gzip, PathBuf::from(stripped))
+    } else if let Some(stripped) = as_str.strip_suffix(".yaz0") {
+        (Compression::Yaz0, PathBuf::from(stripped))
+    } else {
+        (Compression::None, path.to_path_buf())
+    }
+}
+
+pub fn decompress(bytes: &[u8], compression: Compression) -> Vec<u8> {
+    match compression {
+        Compression::None => bytes.to_vec(),
+        Compression::Gzip => gzip_decode(bytes),
+        Compression::Yaz0 => yaz0_decode(bytes),
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut as_os_string = path.as_os_str().to_owned();
+    as_os_string.push(".");
+    as_os_string.push(suffix);
+    PathBuf::from(as_os_string)
+}
+
+fn gzip_encode(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), GzCompression::default());
+    encoder.write_all(bytes).expect("Failed to gzip data.");
+    encoder.finish().expect("Failed to finish gzip stream.")
+}
+
+fn gzip_decode(bytes: &[u8]) -> Vec<u8> {
+    let mut decoder = GzDecoder::new(bytes);
+    let mut out = Vec::new();
+    decoder
+        .read_to_end(&mut out)
+        .expect("Failed to gunzip data.");
+    out
+}
+
+const YAZ0_MAX_DISTANCE: usize = 0x1000;
+const YAZ0_MIN_LENGTH: usize = 3;
+const YAZ0_MAX_LENGTH: usize = 0x111;
+
+/// Classic Yaz0 compression: 16-byte header (`Yaz0`, big-endian decompressed size, 8 reserved
+/// bytes) followed by groups of a control byte plus up to 8 literal bytes or back-references.
+/// Each control-byte bit flags one of the following group entries as a literal (1) or a
+/// back-reference (0); back-references pack a 1..=0x1000 distance and 3..=0x111 length into 2
+/// bytes (length <= 17) or 3 bytes (longer matches).
+fn yaz0_encode(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(16 + data.len());
+    out.extend_from_slice(b"Yaz0");
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(&[0u8; 8]);
+
+    let mut pos = 0;
+    while pos < data.len() {
+        let mut control_byte = 0u8;
+        let mut group = Vec::new();
+
+        for bit in 0..8 {
+            if pos >= data.len() {
+                break;
+            }
+
+            match find_longest_match(data, pos) {
+                Some((distance, length)) => {
+                    let length_minus_two = length - 2;
+                    if length_minus_two < 0x10 {
+                        let byte1 = ((length_minus_two as u8) << 4)
+                            | (((distance - 1) >> 8) as u8 & 0x0F);
+                        let byte2 = ((distance - 1) & 0xFF) as u8;
+                        group.push(byte1);
+                        group.push(byte2);
+                    } else {
+                        let byte1 = (((distance - 1) >> 8) as u8) & 0x0F;
+                        let byte2 = ((distance - 1) & 0xFF) as u8;
+                        let byte3 = (length - 0x12) as u8;
+                        group.push(byte1);
+                        group.push(byte2);
+                        group.push(byte3);
+                    }
+                    pos += length;
+                }
+                None => {
+                    control_byte |= 1 << (7 - bit);
+                    group.push(data[pos]);
+                    pos += 1;
+                }
+            }
+        }
+
+        out.push(control_byte);
+        out.extend_from_slice(&group);
+    }
+
+    out
+}
+
+/// Scans the sliding window behind `pos` for the longest prior match, preferring the closest
+/// one on ties so the distance field stays small.
+fn find_longest_match(data: &[u8], pos: usize) -> Option<(usize, usize)> {
+    if pos == 0 {
+        return None;
+    }
+
+    let window_start = pos.saturating_sub(YAZ0_MAX_DISTANCE);
+    let max_len = (data.len() - pos).min(YAZ0_MAX_LENGTH);
+    if max_len < YAZ0_MIN_LENGTH {
+        return None;
+    }
+
+    let mut best_length = 0;
+    let mut best_distance = 0;
+
+    for start in (window_start..pos).rev() {
+        let mut len = 0;
+        while len < max_len && data[start + len] == data[pos + len] {
+            len += 1;
+        }
+        if len >= YAZ0_MIN_LENGTH && len > best_length {
+            best_length = len;
+            best_distance = pos - start;
+        }
+    }
+
+    if best_length >= YAZ0_MIN_LENGTH {
+        Some((best_distance, best_length))
+    } else {
+        None
+    }
+}
+
+fn yaz0_decode(data: &[u8]) -> Vec<u8> {
+    assert_eq!(&data[0..4], b"Yaz0", "Not a Yaz0-compressed file.");
+    let decompressed_size =
+        u32::from_be_bytes([data[4], data[5], data[6], data[7]]) as usize;
+
+    let mut out = Vec::with_capacity(decompressed_size);
+    let mut pos = 16;
+
+    while out.len() < decompressed_size {
+        let control_byte = data[pos];
+        pos += 1;
+
+        for bit in 0..8 {
+            if out.len() >= decompressed_size {
+                break;
+            }
+
+            if control_byte & (1 << (7 - bit)) != 0 {
+                out.push(data[pos]);
+                pos += 1;
+            } else {
+                let byte1 = data[pos];
+                let byte2 = data[pos + 1];
+                pos += 2;
+
+                let distance = (((byte1 & 0x0F) as usize) << 8 | byte2 as usize) + 1;
+                let length_nibble = byte1 >> 4;
+                let length = if length_nibble == 0 {
+                    let byte3 = data[pos];
+                    pos += 1;
+                    byte3 as usize + 0x12
+                } else {
+                    length_nibble as usize + 2
+                };
+
+                let start = out.len() - distance;
+                for i in 0..length {
+                    let b = out[start + i];
+                    out.push(b);
+                }
+            }
+        }
+    }
+
+    out
+}
@@ -0,0 +1,754 @@
+use std::{fs::File, io::Write, path::Path};
+
+use nalgebra::{point, vector, Isometry3, Point3, Quaternion, UnitQuaternion};
+use rapier3d::parry::transformation::{
+    vhacd::{VHACDParameters, VHACD},
+    voxelization::FillMode,
+};
+
+use clap::{Args as ClapArgs, Parser, ValueEnum};
+use gltf_json as json;
+use json::validation::Checked::Valid;
+use serde::{Deserialize, Serialize};
+
+use crate::compress::{self, Compression};
+
+/// Serialization format used for the single-file `ShapeCollection` output (`--json-only`).
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Msgpack,
+    Bincode,
+}
+
+impl OutputFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Json => "json",
+            OutputFormat::Msgpack => "msgpack",
+            OutputFormat::Bincode => "bincode",
+        }
+    }
+}
+
+/// Container format used when `--gltf-out` is set: a binary `.glb`, or a `.gltf` with the
+/// binary blob embedded inline as a base64 `data:` URI.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GltfFormat {
+    Glb,
+    Gltf,
+}
+
+/// Error produced while decomposing or writing a shape. Carries a human-readable message so
+/// callers such as `batch` can report exactly what went wrong, instead of relying on a caught
+/// panic.
+#[derive(Debug)]
+pub struct ConvertError(pub String);
+
+impl std::fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ConvertError {}
+
+impl From<std::io::Error> for ConvertError {
+    fn from(e: std::io::Error) -> Self {
+        ConvertError(e.to_string())
+    }
+}
+
+/// Conversion knobs shared by the `convert` and `batch` commands.
+#[derive(ClapArgs, Debug, Clone)]
+pub struct ConversionOptions {
+    /// The string to append to created files
+    #[arg(short, long)]
+    pub append: Option<String>,
+
+    /// Max # of hulls to generate
+    #[arg(short, long, default_value_t = 1024)]
+    pub max_hulls: u32,
+
+    /// Voxel resolution
+    #[arg(short, long, default_value_t = 128)]
+    pub voxel_resolution: u32,
+
+    /// Log the output files on creation
+    #[arg(short, long, default_value_t = true)]
+    pub log_success: bool,
+
+    /// Output as a single shape collection file, serialized in the `--format` format
+    #[arg(short, long, default_value_t = false)]
+    pub json_only: bool,
+
+    /// Serialization format for the single-file shape collection output
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Json)]
+    pub format: OutputFormat,
+
+    /// Deduplicate vertices shared between hulls into one global pool, indexed by each shape
+    #[arg(short, long, default_value_t = false)]
+    pub pooled: bool,
+
+    /// Output the decomposed hulls as a single glTF/GLB file instead of per-hull .obj files
+    #[arg(long, default_value_t = false)]
+    pub gltf_out: bool,
+
+    /// Container format to use when `--gltf-out` is set
+    #[arg(long, value_enum, default_value_t = GltfFormat::Glb)]
+    pub gltf_format: GltfFormat,
+
+    /// Combine meshes before voxelization
+    #[arg(short, long, default_value_t = false)]
+    pub combine_meshes: bool,
+
+    /// Compress emitted shape/obj files
+    #[arg(long, value_enum, default_value_t = Compression::None)]
+    pub compress: Compression,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct ConvertArgs {
+    /// Gltf file path
+    #[arg(short, long)]
+    pub gltf_file: String,
+
+    /// Output directory path
+    #[arg(short, long)]
+    pub output_directory: Option<String>,
+
+    #[command(flatten)]
+    pub options: ConversionOptions,
+}
+
+pub fn run(args: ConvertArgs) {
+    let as_string = std::env::current_dir().unwrap().into_os_string();
+    let path_out = as_string.into_string().unwrap();
+
+    if let Err(e) = convert_and_write(
+        &args.gltf_file,
+        &args.output_directory.unwrap_or(path_out),
+        &args.options,
+    ) {
+        eprintln!("{}", e);
+    }
+}
+
+pub fn convert_and_write(
+    path_in: &str,
+    path_out: &str,
+    options: &ConversionOptions,
+) -> Result<(), ConvertError> {
+    let input_path = Path::new(&path_in);
+    let output_path = Path::new(&path_out);
+
+    let (gltf, buffers, _) =
+        gltf::import(input_path).map_err(|e| ConvertError(e.to_string()))?;
+    let mut all_shapes = Vec::new();
+    let mut shape_names: Vec<String> = Vec::new();
+
+    let mut params = VHACDParameters::default();
+    params.max_convex_hulls = options.max_hulls;
+    params.fill_mode = FillMode::FloodFill {
+        detect_cavities: true,
+    };
+    params.resolution = options.voxel_resolution;
+
+    for scene in gltf.scenes() {
+        for node in scene.nodes() {
+            match node.mesh() {
+                Some(m) => {
+                    let mut verts: Vec<Point3<f32>> = Vec::new();
+                    let mut indices: Vec<u32> = Vec::new();
+
+                    for primitive in m.primitives() {
+                        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+                        if let Some(iter) = reader.read_positions() {
+                            for vertex_position in iter {
+                                let point: Point3<f32> = point![
+                                    vertex_position[0],
+                                    vertex_position[1],
+                                    vertex_position[2]
+                                ];
+                                verts.push(point);
+                            }
+                        }
+
+                        if let Some(iter) = reader.read_indices() {
+                            for read_ind in iter.into_u32() {
+                                indices.push(read_ind);
+                            }
+                        }
+                    }
+
+                    let mut tris: Vec<[u32; 3]> = Vec::new();
+                    for c in indices.chunks(3) {
+                        let t = [c[0], c[1], c[2]];
+                        tris.push(t);
+                    }
+
+                    // Apply transform
+                    let translation = node.transform().decomposed().0;
+                    let rotation = node.transform().decomposed().1;
+                    let scale_comp = node.transform().decomposed().2;
+
+                    // The order returned by decompose is different for some reason. Rip my sanity.
+                    let quat = Quaternion::new(rotation[3], rotation[0], rotation[1], rotation[2]);
+                    let eulers = UnitQuaternion::from_quaternion(quat).euler_angles();
+
+                    // Apply scale
+                    verts = verts
+                        .iter()
+                        .map(|v| {
+                            point![
+                                v.x * scale_comp[0],
+                                v.y * scale_comp[1],
+                                v.z * scale_comp[2]
+                            ]
+                        })
+                        .collect();
+
+                    let iso = Isometry3::new(
+                        vector![translation[0], translation[1], translation[2]],
+                        vector![eulers.0, eulers.1, eulers.2],
+                    );
+
+                    // Apply rotation and position
+                    verts = verts.iter().map(|v| iso.transform_point(v)).collect();
+
+                    let name = m.name().unwrap_or("New Obj").to_owned();
+                    all_shapes.push((verts, tris));
+                    shape_names.push(name);
+                }
+                None => {}
+            }
+        }
+    }
+
+    let mut shape_vec_composed = Vec::new();
+    let append = &options.append.clone().unwrap_or("-shape".to_owned());
+
+    if options.combine_meshes {
+        let mut verts: Vec<Point3<f32>> = Vec::new();
+        let mut tris: Vec<[u32; 3]> = Vec::new();
+        for (i, v) in all_shapes.into_iter().enumerate() {
+            verts.extend(&v.0);
+            tris.extend(&v.1);
+
+            let default_name = "Unknown Shape".to_owned();
+            let shape_name_base = shape_names.get(i).unwrap_or(&default_name);
+            println!("[Combine] Appending shape {}", shape_name_base);
+        }
+
+        let item = (verts, tris);
+        shape_vec_composed.push(item);
+    } else {
+        let cloned = all_shapes.iter().cloned();
+        shape_vec_composed.extend(cloned);
+    }
+
+    let mut json_vec_decomposed = Vec::new();
+    let mut named_hulls_decomposed: Vec<(String, (Vec<Point3<f32>>, Vec<[u32; 3]>))> = Vec::new();
+    // There will only be one shape if combine meshes is true
+    for (i, s) in shape_vec_composed.iter().enumerate() {
+        let decomp = VHACD::decompose(&params, &s.0, &s.1, true);
+        let decomposed_hulls = decomp.compute_exact_convex_hulls(&s.0, &s.1);
+
+        let default_name = "Unknown Shape".to_owned();
+        let hull_name_base = shape_names.get(i).unwrap_or(&default_name);
+
+        if options.json_only {
+            json_vec_decomposed.append(&mut decomposed_hulls.clone());
+        } else if options.gltf_out {
+            for (hull_i, hull) in decomposed_hulls.into_iter().enumerate() {
+                let name_w_index = format!("{}{}", hull_name_base, hull_i);
+                named_hulls_decomposed.push((name_w_index, hull));
+            }
+        } else {
+            for (hull_i, hull) in decomposed_hulls.into_iter().enumerate() {
+                let name_w_index = format!("{}{}", hull_name_base, hull_i);
+                match write_mesh_to_obj(output_path, &name_w_index, append, hull, options) {
+                    Ok(_) => {}
+                    Err(e) => eprintln!("{}", e),
+                }
+            }
+        }
+    }
+
+    let name = input_path
+        .file_name()
+        .and_then(|f| f.to_str())
+        .ok_or_else(|| ConvertError(format!("Invalid input file name: {:?}", input_path)))?
+        .to_string();
+
+    if options.json_only {
+        write_shape_collection(output_path, &name, append, json_vec_decomposed, options)?;
+    } else if options.gltf_out {
+        write_meshes_to_gltf(output_path, &name, append, named_hulls_decomposed, options)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SerdePoint3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SerdeShape {
+    pub points: Vec<SerdePoint3>,
+    pub tris: Vec<[u32; 3]>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ShapeCollection {
+    pub shapes: Vec<SerdeShape>,
+}
+
+/// A shape collection with vertices deduplicated into one shared pool; each shape stores only
+/// indices into `vertices` instead of its own fully-expanded point list. Written instead of
+/// `ShapeCollection` when `options.pooled` is set, and transparently expanded back into a
+/// `ShapeCollection` on load.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PooledShapeCollection {
+    pub vertices: Vec<SerdePoint3>,
+    pub shapes: Vec<PooledShape>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PooledShape {
+    pub indices: Vec<u32>,
+    pub tris: Vec<[u32; 3]>,
+}
+
+/// Vertex coordinates are rounded to a grid of `1 / POOL_QUANTIZATION_SCALE` units; positions that
+/// round to the same grid point are treated as the same pooled vertex.
+const POOL_QUANTIZATION_SCALE: f32 = 1e4;
+
+fn quantize(p: &SerdePoint3) -> (i64, i64, i64) {
+    (
+        (p.x * POOL_QUANTIZATION_SCALE).round() as i64,
+        (p.y * POOL_QUANTIZATION_SCALE).round() as i64,
+        (p.z * POOL_QUANTIZATION_SCALE).round() as i64,
+    )
+}
+
+fn pool_shapes(shapes: Vec<SerdeShape>) -> PooledShapeCollection {
+    let mut vertices: Vec<SerdePoint3> = Vec::new();
+    let mut index_of: std::collections::HashMap<(i64, i64, i64), u32> = std::collections::HashMap::new();
+    let mut pooled_shapes = Vec::with_capacity(shapes.len());
+
+    for shape in shapes {
+        let mut indices = Vec::with_capacity(shape.points.len());
+        for point in &shape.points {
+            let key = quantize(point);
+            let index = *index_of.entry(key).or_insert_with(|| {
+                vertices.push(SerdePoint3 {
+                    x: point.x,
+                    y: point.y,
+                    z: point.z,
+                });
+                (vertices.len() - 1) as u32
+            });
+            indices.push(index);
+        }
+        pooled_shapes.push(PooledShape {
+            indices,
+            tris: shape.tris,
+        });
+    }
+
+    PooledShapeCollection {
+        vertices,
+        shapes: pooled_shapes,
+    }
+}
+
+fn unpool_shapes(pooled: PooledShapeCollection) -> ShapeCollection {
+    let PooledShapeCollection { vertices, shapes } = pooled;
+    let shapes = shapes
+        .into_iter()
+        .map(|shape| {
+            let points = shape
+                .indices
+                .iter()
+                .map(|&i| {
+                    let v = &vertices[i as usize];
+                    SerdePoint3 {
+                        x: v.x,
+                        y: v.y,
+                        z: v.z,
+                    }
+                })
+                .collect();
+            SerdeShape {
+                points,
+                tris: shape.tris,
+            }
+        })
+        .collect();
+
+    ShapeCollection { shapes }
+}
+
+/// A pooled output file is named `<name>.pooled.<format>` (optionally followed by a compression
+/// suffix); we just check for that marker rather than adding a dedicated extension.
+fn is_pooled(path: &Path) -> bool {
+    path.to_string_lossy().contains(".pooled.")
+}
+
+/// Writes the decomposed hulls as a single shape collection, serialized with whichever
+/// `options.format` was requested, to a file named after its extension. Deduplicates vertices
+/// into a shared pool first when `options.pooled` is set.
+fn write_shape_collection(
+    directory: &Path,
+    name: &str,
+    append: &str,
+    shapes: Vec<(Vec<Point3<f32>>, Vec<[u32; 3]>)>,
+    options: &ConversionOptions,
+) -> Result<(), ConvertError> {
+    let filename_fmt = format!("{}{}", &name, &append);
+    let mut filename = directory.clone().join(filename_fmt);
+    if options.pooled {
+        filename.set_extension(format!("pooled.{}", options.format.extension()));
+    } else {
+        filename.set_extension(options.format.extension());
+    }
+
+    let mut serde_shapes: Vec<SerdeShape> = Vec::new();
+
+    for shape in shapes {
+        let as_serde_points: Vec<SerdePoint3> = shape
+            .0
+            .iter()
+            .map(|s| SerdePoint3 {
+                x: s.x,
+                y: s.y,
+                z: s.z,
+            })
+            .collect();
+        let serde_shape = SerdeShape {
+            points: as_serde_points,
+            tris: shape.1,
+        };
+        serde_shapes.push(serde_shape);
+    }
+
+    let bytes = if options.pooled {
+        let pooled = pool_shapes(serde_shapes);
+        serialize_with(&pooled, options.format)?
+    } else {
+        let collection = ShapeCollection {
+            shapes: serde_shapes,
+        };
+        serialize_with(&collection, options.format)?
+    };
+
+    let (filename, bytes) = compress::finalize(&filename, &bytes, options.compress);
+
+    let mut file = File::create(&filename)?;
+    file.write_all(&bytes)?;
+
+    if options.log_success {
+        println!("[DONE] Writing file {:?}", &filename);
+    }
+
+    Ok(())
+}
+
+fn serialize_with<T: Serialize>(value: &T, format: OutputFormat) -> Result<Vec<u8>, ConvertError> {
+    let bytes = match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value)
+            .map_err(|e| ConvertError(e.to_string()))?
+            .into_bytes(),
+        OutputFormat::Msgpack => {
+            rmp_serde::to_vec(value).map_err(|e| ConvertError(e.to_string()))?
+        }
+        OutputFormat::Bincode => {
+            bincode::serialize(value).map_err(|e| ConvertError(e.to_string()))?
+        }
+    };
+    Ok(bytes)
+}
+
+fn deserialize_with<T: for<'de> Deserialize<'de>>(bytes: &[u8], extension: &str) -> T {
+    match extension {
+        "msgpack" => rmp_serde::from_slice(bytes).expect("Failed to deserialize msgpack data."),
+        "bincode" => bincode::deserialize(bytes).expect("Failed to deserialize bincode data."),
+        _ => serde_json::from_slice(bytes).expect("Failed to deserialize json data."),
+    }
+}
+
+/// Loads a shape collection previously written by [`write_shape_collection`], transparently
+/// undoing any `.gz`/`.yaz0` compression, expanding a pooled vertex layout back into full
+/// per-shape points, and picking the deserializer based on the remaining extension (`.json`,
+/// `.msgpack`, or `.bincode`).
+pub fn load_shape_collection(path: &Path) -> std::io::Result<ShapeCollection> {
+    let raw = std::fs::read(path)?;
+    let (compression, inner_path) = compress::strip_compression_extension(path);
+    let bytes = compress::decompress(&raw, compression);
+    let extension = inner_path.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+    let collection = if is_pooled(&inner_path) {
+        unpool_shapes(deserialize_with(&bytes, extension))
+    } else {
+        deserialize_with(&bytes, extension)
+    };
+
+    Ok(collection)
+}
+
+/// Writes each decomposed hull out as its own mesh/primitive/node in a single glTF document,
+/// writing either a binary `.glb` or a `.gltf` with the binary blob embedded as a base64 data
+/// URI, per `options.gltf_format`.
+fn write_meshes_to_gltf(
+    directory: &Path,
+    name: &str,
+    append: &str,
+    named_hulls: Vec<(String, (Vec<Point3<f32>>, Vec<[u32; 3]>))>,
+    options: &ConversionOptions,
+) -> Result<(), ConvertError> {
+    let filename_fmt = format!("{}{}", &name, &append);
+    let mut filename = directory.clone().join(filename_fmt);
+    filename.set_extension(match options.gltf_format {
+        GltfFormat::Glb => "glb",
+        GltfFormat::Gltf => "gltf",
+    });
+
+    let mut root = json::Root::default();
+    let mut scene_nodes = Vec::new();
+    let mut bin: Vec<u8> = Vec::new();
+
+    for (hull_name, (verts, tris)) in named_hulls {
+        let positions_offset = bin.len();
+        let mut min = [f32::MAX; 3];
+        let mut max = [f32::MIN; 3];
+        for v in &verts {
+            for (axis, value) in [v.x, v.y, v.z].into_iter().enumerate() {
+                min[axis] = min[axis].min(value);
+                max[axis] = max[axis].max(value);
+            }
+            bin.extend_from_slice(&v.x.to_le_bytes());
+            bin.extend_from_slice(&v.y.to_le_bytes());
+            bin.extend_from_slice(&v.z.to_le_bytes());
+        }
+        let positions_length = bin.len() - positions_offset;
+        pad_to_four_bytes(&mut bin);
+
+        let indices_offset = bin.len();
+        for tri in &tris {
+            for index in tri {
+                bin.extend_from_slice(&index.to_le_bytes());
+            }
+        }
+        let indices_length = bin.len() - indices_offset;
+        pad_to_four_bytes(&mut bin);
+
+        let positions_view = push_buffer_view(
+            &mut root,
+            positions_offset,
+            positions_length,
+            Some(json::buffer::Target::ArrayBuffer),
+        );
+        let positions_accessor = root.push(json::Accessor {
+            buffer_view: Some(positions_view),
+            byte_offset: Some(json::validation::USize64(0)),
+            count: json::validation::USize64(verts.len() as u64),
+            component_type: Valid(json::accessor::GenericComponentType(
+                json::accessor::ComponentType::F32,
+            )),
+            extensions: Default::default(),
+            extras: Default::default(),
+            type_: Valid(json::accessor::Type::Vec3),
+            min: Some(json::serialize::to_value(Vec::from(min)).unwrap()),
+            max: Some(json::serialize::to_value(Vec::from(max)).unwrap()),
+            name: None,
+            normalized: false,
+            sparse: None,
+        });
+
+        let indices_view = push_buffer_view(
+            &mut root,
+            indices_offset,
+            indices_length,
+            Some(json::buffer::Target::ElementArrayBuffer),
+        );
+        let indices_accessor = root.push(json::Accessor {
+            buffer_view: Some(indices_view),
+            byte_offset: Some(json::validation::USize64(0)),
+            count: json::validation::USize64((tris.len() * 3) as u64),
+            component_type: Valid(json::accessor::GenericComponentType(
+                json::accessor::ComponentType::U32,
+            )),
+            extensions: Default::default(),
+            extras: Default::default(),
+            type_: Valid(json::accessor::Type::Scalar),
+            min: None,
+            max: None,
+            name: None,
+            normalized: false,
+            sparse: None,
+        });
+
+        let mut attributes = std::collections::BTreeMap::new();
+        attributes.insert(Valid(json::mesh::Semantic::Positions), positions_accessor);
+
+        let mesh = root.push(json::Mesh {
+            extensions: Default::default(),
+            extras: Default::default(),
+            name: Some(hull_name.clone()),
+            primitives: vec![json::mesh::Primitive {
+                attributes,
+                extensions: Default::default(),
+                extras: Default::default(),
+                indices: Some(indices_accessor),
+                material: None,
+                mode: Valid(json::mesh::Mode::Triangles),
+                targets: None,
+            }],
+            weights: None,
+        });
+
+        let node = root.push(json::Node {
+            mesh: Some(mesh),
+            name: Some(hull_name),
+            ..Default::default()
+        });
+        scene_nodes.push(node);
+    }
+
+    root.buffers.push(json::Buffer {
+        byte_length: json::validation::USize64(bin.len() as u64),
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        uri: None,
+    });
+    root.scenes.push(json::Scene {
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        nodes: scene_nodes,
+    });
+    root.scene = Some(json::Index::new(0));
+
+    if options.gltf_format == GltfFormat::Glb {
+        let json_string =
+            json::serialize::to_string(&root).map_err(|e| ConvertError(e.to_string()))?;
+        let mut json_bytes = json_string.into_bytes();
+        pad_json_to_four_bytes(&mut json_bytes);
+
+        let glb = gltf::binary::Glb {
+            header: gltf::binary::Header {
+                magic: *b"glTF",
+                version: 2,
+                length: (12 + 8 + json_bytes.len() + 8 + bin.len()) as u32,
+            },
+            bin: Some(std::borrow::Cow::Owned(bin)),
+            json: std::borrow::Cow::Owned(json_bytes),
+        };
+
+        let mut file = File::create(&filename)?;
+        glb.to_writer(&mut file)
+            .map_err(|e| ConvertError(e.to_string()))?;
+
+        if options.log_success {
+            println!("[DONE] Writing file {:?}", &filename);
+        }
+    } else {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+        let data_uri = format!(
+            "data:application/octet-stream;base64,{}",
+            STANDARD.encode(&bin)
+        );
+        root.buffers[0].uri = Some(data_uri);
+
+        let json_string = json::serialize::to_string_pretty(&root)
+            .map_err(|e| ConvertError(e.to_string()))?;
+        let mut file = File::create(&filename)?;
+        file.write_all(json_string.as_bytes())?;
+
+        if options.log_success {
+            println!("[DONE] Writing file {:?}", &filename);
+        }
+    }
+
+    Ok(())
+}
+
+fn push_buffer_view(
+    root: &mut json::Root,
+    byte_offset: usize,
+    byte_length: usize,
+    target: Option<json::buffer::Target>,
+) -> json::Index<json::buffer::View> {
+    root.push(json::buffer::View {
+        buffer: json::Index::new(0),
+        byte_length: json::validation::USize64(byte_length as u64),
+        byte_offset: Some(json::validation::USize64(byte_offset as u64)),
+        byte_stride: None,
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        target: target.map(Valid),
+    })
+}
+
+/// glTF buffer views must be 4-byte aligned.
+fn pad_to_four_bytes(bin: &mut Vec<u8>) {
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+}
+
+/// The glTF JSON chunk of a .glb must be padded with spaces to a 4-byte boundary.
+fn pad_json_to_four_bytes(json_bytes: &mut Vec<u8>) {
+    while json_bytes.len() % 4 != 0 {
+        json_bytes.push(b' ');
+    }
+}
+
+fn write_mesh_to_obj(
+    directory: &Path,
+    name: &str,
+    append: &str,
+    shape: (Vec<Point3<f32>>, Vec<[u32; 3]>),
+    options: &ConversionOptions,
+) -> Result<(), ConvertError> {
+    let mut file_cont: Vec<String> = Vec::new();
+    let name_fmt = format!("o {}", &name);
+    file_cont.push(name_fmt);
+
+    for v in shape.0 {
+        let fmt = format!("v {} {} {}", v.x, v.y, v.z);
+        file_cont.push(fmt);
+    }
+
+    for tri in shape.1 {
+        let fmt = format!("f {} {} {}", tri[0] + 1, tri[1] + 1, tri[2] + 1);
+        file_cont.push(fmt);
+    }
+
+    let filename_fmt = format!("{}{}", &name, &append);
+    let mut filename = directory.clone().join(filename_fmt);
+    filename.set_extension("obj");
+
+    let mut bytes = Vec::new();
+    for line in file_cont {
+        bytes.extend_from_slice(line.as_bytes());
+        bytes.push(b'\n');
+    }
+    let (filename, bytes) = compress::finalize(&filename, &bytes, options.compress);
+
+    let mut file = File::create(&filename)?;
+    file.write_all(&bytes)?;
+
+    if options.log_success {
+        println!("[DONE] Writing file {:?}", &filename);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::Parser;
+use nalgebra::Point3;
+
+use crate::compress;
+use crate::convert::load_shape_collection;
+
+#[derive(Parser, Debug, Clone)]
+pub struct VerifyArgs {
+    /// Path to a shape file (a serialized ShapeCollection) or a directory of per-hull .obj files
+    #[arg(short, long)]
+    pub path: String,
+
+    /// Maximum distance a vertex may sit outside one of its own hull's face half-spaces before
+    /// that hull is flagged as non-convex
+    #[arg(short, long, default_value_t = 1e-4)]
+    pub tolerance: f32,
+}
+
+type Hull = (Vec<Point3<f32>>, Vec<[u32; 3]>);
+
+pub fn run(args: VerifyArgs) {
+    let path = Path::new(&args.path);
+    let hulls = match load_hulls(path) {
+        Ok(hulls) => hulls,
+        Err(e) => {
+            eprintln!("[Verify] Failed to load {:?}: {}", path, e);
+            return;
+        }
+    };
+
+    if hulls.is_empty() {
+        println!("[Verify] No hulls found at {:?}", path);
+        return;
+    }
+
+    let mut total_volume = 0.0f32;
+    let mut total_vertices = 0;
+    let mut total_triangles = 0;
+    let mut flagged = 0;
+
+    for (name, (points, tris)) in &hulls {
+        let volume = signed_volume(points, tris);
+        let max_violation = max_convexity_violation(points, tris);
+        let is_convex = max_violation <= args.tolerance;
+
+        total_volume += volume;
+        total_vertices += points.len();
+        total_triangles += tris.len();
+        if !is_convex {
+            flagged += 1;
+        }
+
+        println!(
+            "[Verify] {}: {} vert(s), {} tri(s), volume {:.6}, max face violation {:.6}{}",
+            name,
+            points.len(),
+            tris.len(),
+            volume,
+            max_violation,
+            if is_convex { "" } else { " (NOT CONVEX)" },
+        );
+    }
+
+    println!(
+        "[Verify] {} hull(s), {} vertex(es), {} triangle(s), total volume {:.6}, {} flagged as non-convex",
+        hulls.len(),
+        total_vertices,
+        total_triangles,
+        total_volume,
+        flagged,
+    );
+}
+
+/// Signed volume via summed tetrahedron volumes from the origin: `V = sum(v0 . (v1 x v2)) / 6`.
+fn signed_volume(points: &[Point3<f32>], tris: &[[u32; 3]]) -> f32 {
+    let mut volume = 0.0;
+    for tri in tris {
+        let v0 = points[tri[0] as usize];
+        let v1 = points[tri[1] as usize];
+        let v2 = points[tri[2] as usize];
+        volume += v0.coords.dot(&v1.coords.cross(&v2.coords));
+    }
+    volume / 6.0
+}
+
+/// For each face, checks every vertex against that face's outward half-space and returns the
+/// worst (largest) violation found. A convex, watertight hull with consistent winding should
+/// have every vertex on or behind every one of its own faces.
+fn max_convexity_violation(points: &[Point3<f32>], tris: &[[u32; 3]]) -> f32 {
+    let mut max_violation = 0.0f32;
+
+    for tri in tris {
+        let v0 = points[tri[0] as usize];
+        let v1 = points[tri[1] as usize];
+        let v2 = points[tri[2] as usize];
+        let normal = (v1 - v0).cross(&(v2 - v0));
+        if normal.norm_squared() < f32::EPSILON {
+            continue;
+        }
+        let normal = normal.normalize();
+
+        for p in points {
+            let distance = normal.dot(&(p - v0));
+            if distance > max_violation {
+                max_violation = distance;
+            }
+        }
+    }
+
+    max_violation
+}
+
+fn load_hulls(path: &Path) -> std::io::Result<Vec<(String, Hull)>> {
+    if path.is_dir() {
+        let mut obj_paths: Vec<PathBuf> = fs::read_dir(path)?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| is_obj_file(p))
+            .collect();
+        obj_paths.sort();
+
+        obj_paths.iter().map(|p| parse_obj(p)).collect()
+    } else {
+        let collection = load_shape_collection(path)?;
+        Ok(collection
+            .shapes
+            .into_iter()
+            .enumerate()
+            .map(|(i, shape)| {
+                let points = shape
+                    .points
+                    .iter()
+                    .map(|p| Point3::new(p.x, p.y, p.z))
+                    .collect();
+                (format!("shape{}", i), (points, shape.tris))
+            })
+            .collect())
+    }
+}
+
+/// Matches `.obj`, as well as a compressed `.obj.gz`/`.obj.yaz0`, the way `write_mesh_to_obj`
+/// (optionally wrapped by `compress::finalize`) names its output.
+fn is_obj_file(path: &Path) -> bool {
+    let (_, inner_path) = compress::strip_compression_extension(path);
+    inner_path.extension().and_then(|e| e.to_str()) == Some("obj")
+}
+
+/// Parses the minimal `o`/`v`/`f` subset of the OBJ format emitted by `write_mesh_to_obj`,
+/// transparently undoing any `.gz`/`.yaz0` compression first.
+fn parse_obj(path: &Path) -> std::io::Result<(String, Hull)> {
+    let raw = fs::read(path)?;
+    let (compression, inner_path) = compress::strip_compression_extension(path);
+    let bytes = compress::decompress(&raw, compression);
+    let contents = String::from_utf8_lossy(&bytes);
+
+    let mut name = inner_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("hull")
+        .to_owned();
+    let mut points = Vec::new();
+    let mut tris = Vec::new();
+
+    for line in contents.lines() {
+        let mut fields = line.split_whitespace();
+        match fields.next() {
+            Some("o") => {
+                if let Some(obj_name) = fields.next() {
+                    name = obj_name.to_owned();
+                }
+            }
+            Some("v") => {
+                let x: f32 = fields.next().unwrap_or("0").parse().unwrap_or(0.0);
+                let y: f32 = fields.next().unwrap_or("0").parse().unwrap_or(0.0);
+                let z: f32 = fields.next().unwrap_or("0").parse().unwrap_or(0.0);
+                points.push(Point3::new(x, y, z));
+            }
+            Some("f") => {
+                let indices: Vec<u32> = fields
+                    .filter_map(|f| f.parse::<u32>().ok())
+                    .map(|i| i - 1)
+                    .collect();
+                if indices.len() == 3 {
+                    tris.push([indices[0], indices[1], indices[2]]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok((name, (points, tris)))
+}